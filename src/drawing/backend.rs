@@ -0,0 +1,149 @@
+use std::error::Error;
+
+use crate::style::{Color, FontDesc};
+
+/// A pixel coordinate on a `DrawingBackend`'s target surface.
+pub type BackendCoord = (i32, i32);
+
+/// The error produced by a `DrawingBackend`: either a font error or a
+/// backend-specific drawing error.
+#[derive(Debug)]
+pub enum DrawingErrorKind<E: Error + Send + Sync> {
+    FontError(Box<dyn Error + Send + Sync>),
+    DrawingError(E),
+}
+
+impl<E: Error + Send + Sync> std::fmt::Display for DrawingErrorKind<E> {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            DrawingErrorKind::FontError(e) => write!(fmt, "Font error: {}", e),
+            DrawingErrorKind::DrawingError(e) => write!(fmt, "Drawing error: {}", e),
+        }
+    }
+}
+
+impl<E: Error + Send + Sync> Error for DrawingErrorKind<E> {}
+
+/// A surface plotters can draw onto: pixels, lines, rectangles, paths,
+/// circles and text, addressed in `BackendCoord` pixel space.
+pub trait DrawingBackend: Sized {
+    type ErrorType: Error + Send + Sync;
+
+    /// Get the dimension of the drawing backend in pixels
+    fn get_size(&self) -> (u32, u32);
+
+    fn open(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>>;
+
+    fn close(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>>;
+
+    fn draw_pixel<C: Color>(
+        &mut self,
+        point: BackendCoord,
+        color: &C,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>>;
+
+    fn draw_line<C: Color>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        color: &C,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>>;
+
+    fn draw_rect<C: Color>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        color: &C,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>>;
+
+    fn draw_path<C: Color, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        color: &C,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>>;
+
+    fn draw_circle<C: Color>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        color: &C,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>>;
+
+    fn draw_text<'a, C: Color>(
+        &mut self,
+        text: &str,
+        font: &FontDesc<'a>,
+        pos: BackendCoord,
+        color: &C,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>>;
+
+    /// Draw a quadratic Bezier curve from `from` to `to` via control point
+    /// `ctrl`. Default: flatten to a polyline through `draw_path`; backends
+    /// that can render curves natively (e.g. `CanvasBackend`) should
+    /// override this.
+    fn draw_quadratic_curve<C: Color>(
+        &mut self,
+        from: BackendCoord,
+        ctrl: BackendCoord,
+        to: BackendCoord,
+        color: &C,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.draw_path(flatten_quadratic_curve(from, ctrl, to), color)
+    }
+
+    /// Draw a cubic Bezier curve from `from` to `to` via control points
+    /// `ctrl1`/`ctrl2`. Default: flatten to a polyline through `draw_path`;
+    /// backends that can render curves natively (e.g. `CanvasBackend`)
+    /// should override this.
+    fn draw_bezier<C: Color>(
+        &mut self,
+        from: BackendCoord,
+        ctrl1: BackendCoord,
+        ctrl2: BackendCoord,
+        to: BackendCoord,
+        color: &C,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.draw_path(flatten_bezier_curve(from, ctrl1, ctrl2, to), color)
+    }
+}
+
+/// Number of line segments used to flatten a curve in the default
+/// `draw_quadratic_curve`/`draw_bezier` implementations.
+const CURVE_SEGMENTS: usize = 16;
+
+fn flatten_quadratic_curve(from: BackendCoord, ctrl: BackendCoord, to: BackendCoord) -> Vec<BackendCoord> {
+    (0..=CURVE_SEGMENTS)
+        .map(|i| {
+            let t = i as f64 / CURVE_SEGMENTS as f64;
+            let mt = 1.0 - t;
+            let x = mt * mt * from.0 as f64 + 2.0 * mt * t * ctrl.0 as f64 + t * t * to.0 as f64;
+            let y = mt * mt * from.1 as f64 + 2.0 * mt * t * ctrl.1 as f64 + t * t * to.1 as f64;
+            (x.round() as i32, y.round() as i32)
+        })
+        .collect()
+}
+
+fn flatten_bezier_curve(
+    from: BackendCoord,
+    ctrl1: BackendCoord,
+    ctrl2: BackendCoord,
+    to: BackendCoord,
+) -> Vec<BackendCoord> {
+    (0..=CURVE_SEGMENTS)
+        .map(|i| {
+            let t = i as f64 / CURVE_SEGMENTS as f64;
+            let mt = 1.0 - t;
+            let x = mt * mt * mt * from.0 as f64
+                + 3.0 * mt * mt * t * ctrl1.0 as f64
+                + 3.0 * mt * t * t * ctrl2.0 as f64
+                + t * t * t * to.0 as f64;
+            let y = mt * mt * mt * from.1 as f64
+                + 3.0 * mt * mt * t * ctrl1.1 as f64
+                + 3.0 * mt * t * t * ctrl2.1 as f64
+                + t * t * t * to.1 as f64;
+            (x.round() as i32, y.round() as i32)
+        })
+        .collect()
+}
@@ -1,6 +1,6 @@
 use js_sys::JSON;
-use wasm_bindgen::{JsCast, JsValue};
-use web_sys::{window, CanvasRenderingContext2d, HtmlCanvasElement};
+use wasm_bindgen::{Clamped, JsCast, JsValue};
+use web_sys::{window, CanvasRenderingContext2d, HtmlCanvasElement, HtmlImageElement};
 
 use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 use crate::style::{Color, FontDesc};
@@ -9,8 +9,34 @@ use crate::style::{Color, FontDesc};
 pub struct CanvasBackend {
     canvas: HtmlCanvasElement,
     context: CanvasRenderingContext2d,
+    /// Stack of affine transforms `[a, b, c, d, e, f]` (the canvas
+    /// `setTransform` matrix `[[a c e],[b d f]]`) pushed by `save()` and
+    /// popped by `restore()`. The last entry is always the active transform.
+    transform_stack: Vec<[f64; 6]>,
+    /// Offscreen canvases pushed by `push_layer()`, each with its own 2D
+    /// context. Drawing is always routed to the top of this stack, falling
+    /// back to the base `canvas`/`context` when it is empty.
+    layers: Vec<(HtmlCanvasElement, CanvasRenderingContext2d)>,
+    /// Current stroke width, applied to `draw_line`/`draw_path` before
+    /// stroking.
+    line_width: f64,
+    /// Current dash pattern, applied to `draw_line`/`draw_path` before
+    /// stroking. Empty means a solid line.
+    line_dash: Vec<f64>,
+    /// Current line cap style (`"butt"`, `"round"`, or `"square"`).
+    line_cap: String,
+    /// Current line join style (`"miter"`, `"round"`, or `"bevel"`).
+    line_join: String,
 }
 
+/// The default, solid 1px line style the backend starts and resets with.
+const DEFAULT_LINE_WIDTH: f64 = 1.0;
+const DEFAULT_LINE_CAP: &str = "butt";
+const DEFAULT_LINE_JOIN: &str = "miter";
+
+/// The identity affine transform.
+const IDENTITY_TRANSFORM: [f64; 6] = [1.0, 0.0, 0.0, 1.0, 0.0, 0.0];
+
 pub struct CanvasError(JsValue);
 
 impl std::fmt::Display for CanvasError {
@@ -39,33 +65,422 @@ impl std::fmt::Debug for CanvasError {
 
 impl std::error::Error for CanvasError {}
 
+/// Options controlling how `CanvasBackend` creates its 2D rendering context.
+pub struct CanvasOptions {
+    /// Whether the canvas keeps an alpha channel. Set to `false` for opaque
+    /// dashboards so the browser can skip per-pixel blending.
+    pub alpha: bool,
+    /// Hint that presentation may be desynchronized from the compositor,
+    /// reducing input-to-paint latency for animated or streaming plots.
+    pub desynchronized: bool,
+}
+
+impl Default for CanvasOptions {
+    fn default() -> Self {
+        return CanvasOptions {
+            alpha: true,
+            desynchronized: false,
+        };
+    }
+}
+
+/// A fill style for shapes drawn on the canvas. Unlike the flat `Color` used
+/// by the core `DrawingBackend` trait, this lets canvas-specific drawing
+/// code express gradients and image patterns that the browser can render
+/// natively.
+pub enum BackendFillStyle<'a, C: Color> {
+    /// A single flat color.
+    Solid(C),
+    /// A linear gradient between two points, with color stops expressed as
+    /// `(offset, color)` pairs where `offset` is in `[0, 1]`.
+    LinearGradient {
+        from: BackendCoord,
+        to: BackendCoord,
+        stops: Vec<(f64, C)>,
+    },
+    /// A radial gradient centered at `center` out to `radius`.
+    RadialGradient {
+        center: BackendCoord,
+        radius: f64,
+        stops: Vec<(f64, C)>,
+    },
+    /// A repeating image pattern, with `repeat` one of the canvas
+    /// repetition keywords (`"repeat"`, `"repeat-x"`, `"repeat-y"`,
+    /// `"no-repeat"`).
+    Pattern {
+        image: &'a HtmlImageElement,
+        repeat: &'a str,
+    },
+}
+
 impl CanvasBackend {
     /// Create a new drawing backend backed with an HTML5 canvas object
     /// - `elem_id` The element id for the canvas
     /// - Return either some drawing backend has been created, or none in error case
     pub fn new(elem_id: &str) -> Option<Self> {
+        return Self::with_options(elem_id, CanvasOptions::default());
+    }
+
+    /// Create a new drawing backend backed with an HTML5 canvas object,
+    /// configuring the underlying 2D context with `options`.
+    /// - `elem_id` The element id for the canvas
+    /// - `options` Context creation options, e.g. to request an opaque or
+    ///   desynchronized context
+    /// - Return either some drawing backend has been created, or none in error case
+    pub fn with_options(elem_id: &str, options: CanvasOptions) -> Option<Self> {
         let document = window()?.document()?;
         let canvas = document.get_element_by_id(elem_id)?;
         let canvas: HtmlCanvasElement = canvas.dyn_into().ok()?;
-        let context: CanvasRenderingContext2d = canvas.get_context("2d").ok()??.dyn_into().ok()?;
-        return Some(CanvasBackend { canvas, context });
+        let context_options = js_sys::Object::new();
+        js_sys::Reflect::set(&context_options, &"alpha".into(), &options.alpha.into()).ok()?;
+        js_sys::Reflect::set(
+            &context_options,
+            &"desynchronized".into(),
+            &options.desynchronized.into(),
+        )
+        .ok()?;
+        let context: CanvasRenderingContext2d = canvas
+            .get_context_with_context_options("2d", &context_options)
+            .ok()??
+            .dyn_into()
+            .ok()?;
+        return Some(CanvasBackend {
+            canvas,
+            context,
+            transform_stack: vec![IDENTITY_TRANSFORM],
+            layers: Vec::new(),
+            line_width: DEFAULT_LINE_WIDTH,
+            line_dash: Vec::new(),
+            line_cap: DEFAULT_LINE_CAP.to_string(),
+            line_join: DEFAULT_LINE_JOIN.to_string(),
+        });
+    }
+
+    /// The canvas currently receiving draw calls: the topmost pushed layer,
+    /// or the base canvas if no layer is active.
+    fn current_canvas(&self) -> &HtmlCanvasElement {
+        match self.layers.last() {
+            Some((canvas, _)) => canvas,
+            None => &self.canvas,
+        }
+    }
+
+    /// The 2D context currently receiving draw calls: the topmost pushed
+    /// layer's context, or the base context if no layer is active.
+    fn current_context(&self) -> &CanvasRenderingContext2d {
+        match self.layers.last() {
+            Some((_, context)) => context,
+            None => &self.context,
+        }
+    }
+
+    /// Allocate a new offscreen canvas the same size as the base canvas and
+    /// redirect all subsequent drawing to it. Pair with `pop_layer()` to
+    /// composite it back onto the parent.
+    pub fn push_layer(&mut self) -> Result<(), CanvasError> {
+        let (width, height) = self.get_size();
+        let document = window()
+            .and_then(|w| w.document())
+            .ok_or_else(|| CanvasError(JsValue::from_str("no document available")))?;
+        let element = document.create_element("canvas").map_err(CanvasError)?;
+        let layer_canvas: HtmlCanvasElement = element
+            .dyn_into()
+            .map_err(|_| CanvasError(JsValue::from_str("failed to create a canvas element")))?;
+        layer_canvas.set_width(width);
+        layer_canvas.set_height(height);
+        let layer_context: CanvasRenderingContext2d = layer_canvas
+            .get_context("2d")
+            .map_err(CanvasError)?
+            .ok_or_else(|| CanvasError(JsValue::from_str("2d context unavailable")))?
+            .dyn_into()
+            .map_err(|_| CanvasError(JsValue::from_str("context is not a CanvasRenderingContext2d")))?;
+        let [a, b, c, d, e, f] = *self.transform_stack.last().unwrap_or(&IDENTITY_TRANSFORM);
+        layer_context.set_transform(a, b, c, d, e, f).map_err(CanvasError)?;
+        self.layers.push((layer_canvas, layer_context));
+        return Ok(());
+    }
+
+    /// Pop the topmost offscreen layer and composite it onto whatever is now
+    /// the current target (the next layer down, or the base canvas). The
+    /// layer was rasterized in device space already (its context was seeded
+    /// with the active transform in `push_layer()`), so the destination's
+    /// transform is neutralized around the blit to avoid applying it twice.
+    pub fn pop_layer(&mut self) -> Result<(), CanvasError> {
+        if let Some((layer_canvas, _)) = self.layers.pop() {
+            self.save();
+            let blit = self
+                .current_context()
+                .set_transform(1.0, 0.0, 0.0, 1.0, 0.0, 0.0)
+                .map_err(CanvasError)
+                .and_then(|_| {
+                    self.current_context()
+                        .draw_image_with_html_canvas_element(&layer_canvas, 0.0, 0.0)
+                        .map_err(CanvasError)
+                });
+            self.restore();
+            blit?;
+            let [a, b, c, d, e, f] = *self.transform_stack.last().unwrap_or(&IDENTITY_TRANSFORM);
+            self.current_context()
+                .set_transform(a, b, c, d, e, f)
+                .map_err(CanvasError)?;
+        }
+        return Ok(());
+    }
+
+    /// Restrict subsequent drawing on the current target to the given
+    /// rectangle. Implemented as a canvas `save()` plus `clip()`, so a
+    /// matching `clear_clip()` is required to lift the restriction.
+    pub fn set_clip_rect(&mut self, upper_left: BackendCoord, bottom_right: BackendCoord) {
+        self.save();
+        let context = self.current_context();
+        context.begin_path();
+        context.rect(
+            upper_left.0 as f64,
+            upper_left.1 as f64,
+            (bottom_right.0 - upper_left.0) as f64,
+            (bottom_right.1 - upper_left.1) as f64,
+        );
+        context.clip();
+    }
+
+    /// Lift the clip region installed by `set_clip_rect()`.
+    pub fn clear_clip(&mut self) {
+        self.restore();
+    }
+
+    /// Set the current affine transform, matching the canvas `setTransform`
+    /// convention `[[a c e],[b d f]]`.
+    pub fn set_transform(&mut self, a: f64, b: f64, c: f64, d: f64, e: f64, f: f64) -> Result<(), CanvasError> {
+        self.current_context()
+            .set_transform(a, b, c, d, e, f)
+            .map_err(CanvasError)?;
+        if let Some(top) = self.transform_stack.last_mut() {
+            *top = [a, b, c, d, e, f];
+        }
+        return Ok(());
+    }
+
+    /// Push the current transform onto the stack and save the canvas state
+    /// of the current target, mirroring the canvas `save()` call.
+    pub fn save(&mut self) {
+        self.current_context().save();
+        let current = *self.transform_stack.last().unwrap_or(&IDENTITY_TRANSFORM);
+        self.transform_stack.push(current);
+    }
+
+    /// Pop the most recently saved transform off the stack and restore the
+    /// canvas state of the current target, mirroring the canvas `restore()`
+    /// call.
+    pub fn restore(&mut self) {
+        self.current_context().restore();
+        if self.transform_stack.len() > 1 {
+            self.transform_stack.pop();
+        }
+    }
+
+    /// Read back the rendered pixels of the current target as an RGBA byte
+    /// buffer, four bytes per pixel in row-major order.
+    pub fn get_image_data(
+        &self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+    ) -> Result<Vec<u8>, CanvasError> {
+        let width = (bottom_right.0 - upper_left.0) as f64;
+        let height = (bottom_right.1 - upper_left.1) as f64;
+        let image_data = self
+            .current_context()
+            .get_image_data(upper_left.0 as f64, upper_left.1 as f64, width, height)
+            .map_err(CanvasError)?;
+        return Ok(image_data.data().0);
+    }
+
+    /// Blit a precomputed RGBA byte buffer onto the current target at
+    /// `upper_left`, cheaper than drawing it pixel by pixel.
+    pub fn put_image_data(
+        &mut self,
+        data: &[u8],
+        upper_left: BackendCoord,
+        width: u32,
+        height: u32,
+    ) -> Result<(), CanvasError> {
+        let image_data =
+            web_sys::ImageData::new_with_u8_clamped_array_and_sh(Clamped(data), width, height)
+                .map_err(CanvasError)?;
+        self.current_context()
+            .put_image_data(&image_data, upper_left.0 as f64, upper_left.1 as f64)
+            .map_err(CanvasError)?;
+        return Ok(());
+    }
+
+    /// Export the current target as a data URL, e.g. `"image/png"` or
+    /// `"image/jpeg"`, so a finished plot can be downloaded from the browser.
+    pub fn to_data_url(&self, mime: &str) -> Result<String, CanvasError> {
+        return self.current_canvas().to_data_url_with_type(mime).map_err(CanvasError);
+    }
+
+    /// Resolve a `BackendFillStyle` into a canvas fill style value, creating
+    /// a `CanvasGradient`/`CanvasPattern` on the current target as needed.
+    fn resolve_fill_style<C: Color>(&self, style: &BackendFillStyle<C>) -> Result<JsValue, CanvasError> {
+        let context = self.current_context();
+        return match style {
+            BackendFillStyle::Solid(color) => Ok(make_canvas_color(color)),
+            BackendFillStyle::LinearGradient { from, to, stops } => {
+                let gradient =
+                    context.create_linear_gradient(from.0 as f64, from.1 as f64, to.0 as f64, to.1 as f64);
+                for (offset, color) in stops {
+                    gradient
+                        .add_color_stop(*offset as f32, &canvas_color_string(color))
+                        .map_err(CanvasError)?;
+                }
+                Ok(gradient.into())
+            }
+            BackendFillStyle::RadialGradient {
+                center,
+                radius,
+                stops,
+            } => {
+                let gradient = context
+                    .create_radial_gradient(
+                        center.0 as f64,
+                        center.1 as f64,
+                        0.0,
+                        center.0 as f64,
+                        center.1 as f64,
+                        *radius,
+                    )
+                    .map_err(CanvasError)?;
+                for (offset, color) in stops {
+                    gradient
+                        .add_color_stop(*offset as f32, &canvas_color_string(color))
+                        .map_err(CanvasError)?;
+                }
+                Ok(gradient.into())
+            }
+            BackendFillStyle::Pattern { image, repeat } => {
+                let pattern = context
+                    .create_pattern_with_html_image_element(image, repeat)
+                    .map_err(CanvasError)?
+                    .ok_or_else(|| CanvasError(JsValue::from_str("failed to create pattern")))?;
+                Ok(pattern.into())
+            }
+        };
+    }
+
+    /// Fill a rectangle with a gradient or pattern fill style.
+    pub fn draw_rect_with_fill_style<C: Color>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &BackendFillStyle<C>,
+    ) -> Result<(), DrawingErrorKind<CanvasError>> {
+        let fill = self
+            .resolve_fill_style(style)
+            .map_err(DrawingErrorKind::DrawingError)?;
+        let context = self.current_context();
+        context.set_fill_style(&fill);
+        context.fill_rect(
+            upper_left.0 as f64,
+            upper_left.1 as f64,
+            (bottom_right.0 - upper_left.0) as f64,
+            (bottom_right.1 - upper_left.1) as f64,
+        );
+        return Ok(());
+    }
+
+    /// Set the stroke width applied to subsequent `draw_line`/`draw_path`
+    /// calls.
+    pub fn set_line_width(&mut self, width: f64) {
+        self.line_width = width;
+    }
+
+    /// Set the dash pattern applied to subsequent `draw_line`/`draw_path`
+    /// calls. An empty slice draws a solid line.
+    pub fn set_line_dash(&mut self, dash: &[f64]) {
+        self.line_dash = dash.to_vec();
+    }
+
+    /// Set the line cap style (`"butt"`, `"round"`, or `"square"`) applied
+    /// to subsequent `draw_line`/`draw_path` calls.
+    pub fn set_line_cap(&mut self, cap: &str) {
+        self.line_cap = cap.to_string();
+    }
+
+    /// Set the line join style (`"miter"`, `"round"`, or `"bevel"`) applied
+    /// to subsequent `draw_line`/`draw_path` calls.
+    pub fn set_line_join(&mut self, join: &str) {
+        self.line_join = join.to_string();
+    }
+
+    /// Apply the current line width, dash pattern, cap, and join to the
+    /// current target ahead of a stroke.
+    fn apply_line_style(&self) -> Result<(), CanvasError> {
+        let context = self.current_context();
+        context.set_line_width(self.line_width);
+        context.set_line_cap(&self.line_cap);
+        context.set_line_join(&self.line_join);
+        let dash = js_sys::Array::new();
+        for segment in &self.line_dash {
+            dash.push(&JsValue::from_f64(*segment));
+        }
+        context.set_line_dash(&dash).map_err(CanvasError)?;
+        return Ok(());
+    }
+
+    /// Fill a circle with a gradient or pattern fill style.
+    pub fn draw_circle_with_fill_style<C: Color>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &BackendFillStyle<C>,
+    ) -> Result<(), DrawingErrorKind<CanvasError>> {
+        let fill = self
+            .resolve_fill_style(style)
+            .map_err(DrawingErrorKind::DrawingError)?;
+        let context = self.current_context();
+        context.set_fill_style(&fill);
+        context.begin_path();
+        context
+            .arc(
+                center.0 as f64,
+                center.1 as f64,
+                radius as f64,
+                0.0,
+                std::f64::consts::PI * 2.0,
+            )
+            .map_err(|e| DrawingErrorKind::DrawingError(CanvasError(e)))?;
+        context.fill();
+        return Ok(());
     }
 }
 
-fn make_canvas_color<C: Color>(color: &C) -> JsValue {
+fn canvas_color_string<C: Color>(color: &C) -> String {
     let (r, g, b) = color.rgb();
     let a = color.alpha();
-    return format!("rgba({},{},{},{})", r, g, b, a).into();
+    return format!("rgba({},{},{},{})", r, g, b, a);
+}
+
+fn make_canvas_color<C: Color>(color: &C) -> JsValue {
+    return canvas_color_string(color).into();
 }
 
 impl DrawingBackend for CanvasBackend {
     type ErrorType = CanvasError;
 
     fn get_size(&self) -> (u32, u32) {
-        return (self.canvas.width(), self.canvas.height());
+        let canvas = self.current_canvas();
+        return (canvas.width(), canvas.height());
     }
 
     fn open(&mut self) -> Result<(), DrawingErrorKind<CanvasError>> {
+        self.transform_stack.clear();
+        self.transform_stack.push(IDENTITY_TRANSFORM);
+        self.context
+            .set_transform(1.0, 0.0, 0.0, 1.0, 0.0, 0.0)
+            .map_err(|e| DrawingErrorKind::DrawingError(CanvasError(e)))?;
+        self.line_width = DEFAULT_LINE_WIDTH;
+        self.line_dash.clear();
+        self.line_cap = DEFAULT_LINE_CAP.to_string();
+        self.line_join = DEFAULT_LINE_JOIN.to_string();
         return Ok(());
     }
 
@@ -78,9 +493,9 @@ impl DrawingBackend for CanvasBackend {
         point: BackendCoord,
         color: &C,
     ) -> Result<(), DrawingErrorKind<CanvasError>> {
-        self.context.set_fill_style(&make_canvas_color(color));
-        self.context
-            .fill_rect(point.0 as f64, point.1 as f64, 1.0, 1.0);
+        let context = self.current_context();
+        context.set_fill_style(&make_canvas_color(color));
+        context.fill_rect(point.0 as f64, point.1 as f64, 1.0, 1.0);
         return Ok(());
     }
 
@@ -90,11 +505,13 @@ impl DrawingBackend for CanvasBackend {
         to: BackendCoord,
         color: &C,
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
-        self.context.set_stroke_style(&make_canvas_color(color));
-        self.context.begin_path();
-        self.context.move_to(from.0 as f64, from.1 as f64);
-        self.context.line_to(to.0 as f64, to.1 as f64);
-        self.context.stroke();
+        self.apply_line_style().map_err(DrawingErrorKind::DrawingError)?;
+        let context = self.current_context();
+        context.set_stroke_style(&make_canvas_color(color));
+        context.begin_path();
+        context.move_to(from.0 as f64, from.1 as f64);
+        context.line_to(to.0 as f64, to.1 as f64);
+        context.stroke();
         return Ok(());
     }
 
@@ -105,17 +522,18 @@ impl DrawingBackend for CanvasBackend {
         color: &C,
         fill: bool,
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let context = self.current_context();
         if fill {
-            self.context.set_fill_style(&make_canvas_color(color));
-            self.context.fill_rect(
+            context.set_fill_style(&make_canvas_color(color));
+            context.fill_rect(
                 upper_left.0 as f64,
                 upper_left.1 as f64,
                 (bottom_right.0 - upper_left.0) as f64,
                 (bottom_right.1 - upper_left.1) as f64,
             );
         } else {
-            self.context.set_stroke_style(&make_canvas_color(color));
-            self.context.stroke_rect(
+            context.set_stroke_style(&make_canvas_color(color));
+            context.stroke_rect(
                 upper_left.0 as f64,
                 upper_left.1 as f64,
                 (bottom_right.0 - upper_left.0) as f64,
@@ -130,16 +548,18 @@ impl DrawingBackend for CanvasBackend {
         path: I,
         color: &C,
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.apply_line_style().map_err(DrawingErrorKind::DrawingError)?;
         let mut path = path.into_iter();
-        self.context.begin_path();
+        let context = self.current_context();
+        context.begin_path();
         if let Some(start) = path.next() {
-            self.context.set_stroke_style(&make_canvas_color(color));
-            self.context.move_to(start.0 as f64, start.1 as f64);
+            context.set_stroke_style(&make_canvas_color(color));
+            context.move_to(start.0 as f64, start.1 as f64);
             for next in path {
-                self.context.line_to(next.0 as f64, next.1 as f64);
+                context.line_to(next.0 as f64, next.1 as f64);
             }
         }
-        self.context.stroke();
+        context.stroke();
         return Ok(());
     }
 
@@ -150,13 +570,14 @@ impl DrawingBackend for CanvasBackend {
         color: &C,
         fill: bool,
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let context = self.current_context();
         if fill {
-            self.context.set_fill_style(&make_canvas_color(color));
+            context.set_fill_style(&make_canvas_color(color));
         } else {
-            self.context.set_stroke_style(&make_canvas_color(color));
+            context.set_stroke_style(&make_canvas_color(color));
         }
-        self.context.begin_path();
-        self.context
+        context.begin_path();
+        context
             .arc(
                 center.0 as f64,
                 center.1 as f64,
@@ -166,9 +587,9 @@ impl DrawingBackend for CanvasBackend {
             )
             .map_err(|e| DrawingErrorKind::DrawingError(CanvasError(e)))?;
         if fill {
-            self.context.fill();
+            context.fill();
         } else {
-            self.context.stroke();
+            context.stroke();
         }
         return Ok(());
     }
@@ -180,13 +601,53 @@ impl DrawingBackend for CanvasBackend {
         pos: BackendCoord,
         color: &C,
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
-        self.context.set_text_baseline("bottom");
-        self.context.set_fill_style(&make_canvas_color(color));
-        self.context
-            .set_font(&format!("{}px {}", font.get_size(), font.get_name()));
-        self.context
+        let context = self.current_context();
+        context.set_text_baseline("bottom");
+        context.set_fill_style(&make_canvas_color(color));
+        context.set_font(&format!("{}px {}", font.get_size(), font.get_name()));
+        context
             .fill_text(text, pos.0 as f64, pos.1 as f64 + font.get_size())
             .map_err(|e| DrawingErrorKind::DrawingError(CanvasError(e)))?;
         return Ok(());
     }
+
+    fn draw_quadratic_curve<C: Color>(
+        &mut self,
+        from: BackendCoord,
+        ctrl: BackendCoord,
+        to: BackendCoord,
+        color: &C,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let context = self.current_context();
+        context.set_stroke_style(&make_canvas_color(color));
+        context.begin_path();
+        context.move_to(from.0 as f64, from.1 as f64);
+        context.quadratic_curve_to(ctrl.0 as f64, ctrl.1 as f64, to.0 as f64, to.1 as f64);
+        context.stroke();
+        return Ok(());
+    }
+
+    fn draw_bezier<C: Color>(
+        &mut self,
+        from: BackendCoord,
+        ctrl1: BackendCoord,
+        ctrl2: BackendCoord,
+        to: BackendCoord,
+        color: &C,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let context = self.current_context();
+        context.set_stroke_style(&make_canvas_color(color));
+        context.begin_path();
+        context.move_to(from.0 as f64, from.1 as f64);
+        context.bezier_curve_to(
+            ctrl1.0 as f64,
+            ctrl1.1 as f64,
+            ctrl2.0 as f64,
+            ctrl2.1 as f64,
+            to.0 as f64,
+            to.1 as f64,
+        );
+        context.stroke();
+        return Ok(());
+    }
 }